@@ -36,12 +36,31 @@
 //!     Err(e) => eprintln!("Error: {}", e),
 //! }
 //! ```
+//!
+//! ## Resolving many paths at once
+//!
+//! [`DeviceResolver`] exposes the same resolution logic as a reusable struct
+//! with overridable `/sys`, `/dev`, and mountinfo roots. Reusing one
+//! resolver across a batch of lookups parses mountinfo at most once instead
+//! of on every call:
+//!
+//! ```rust,no_run
+//! use blkpath::DeviceResolver;
+//!
+//! let resolver = DeviceResolver::new();
+//! for dev in [0x0801_u64, 0x0802] {
+//!     println!("{}", resolver.resolve(dev)?.display());
+//! }
+//! # Ok::<(), blkpath::DeviceResolveError>(())
+//! ```
 
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader};
 use std::os::unix::fs::MetadataExt;
 use std::os::unix::io::AsRawFd;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use thiserror::Error;
 
@@ -86,6 +105,10 @@ pub enum DeviceResolveError {
     /// Failed to call fstat on file descriptor.
     #[error("Failed to fstat file descriptor: {0}")]
     FstatError(String),
+
+    /// Failed to parse or validate a "major:minor" device number string.
+    #[error("Invalid device number {0:?}")]
+    InvalidDevnum(String),
 }
 
 /// A trait for resolving the underlying block device of a file or path.
@@ -117,6 +140,110 @@ pub trait ResolveDevice {
     /// # Ok::<(), blkpath::DeviceResolveError>(())
     /// ```
     fn resolve_device(&self) -> Result<PathBuf, DeviceResolveError>;
+
+    /// Resolves the containing whole-disk device path.
+    ///
+    /// If the file or directory lives on a partition (e.g. `/dev/sda1` or
+    /// `/dev/nvme0n1p2`), this returns the parent whole disk (`/dev/sda` or
+    /// `/dev/nvme0n1`) rather than the partition itself. If the underlying
+    /// device is already a whole disk, it is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if:
+    /// - The file/path cannot be accessed
+    /// - The device information cannot be retrieved
+    /// - The parent disk cannot be mapped to a block device path
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use blkpath::ResolveDevice;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/home");
+    /// let disk = path.resolve_disk()?;
+    /// println!("Disk: {}", disk.display());
+    /// # Ok::<(), blkpath::DeviceResolveError>(())
+    /// ```
+    fn resolve_disk(&self) -> Result<PathBuf, DeviceResolveError>;
+
+    /// Resolves the leaf physical devices backing this file or path.
+    ///
+    /// For a plain partition or whole disk this returns a single-element
+    /// vector containing the same path `resolve_device` would. For a file
+    /// on a device-mapper/LVM logical volume or an mdraid array, this
+    /// descends the `slaves/` stacking in sysfs and returns every physical
+    /// block device backing it (deduplicated).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if:
+    /// - The file/path cannot be accessed
+    /// - The device information cannot be retrieved
+    /// - The device cannot be found in sysfs
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use blkpath::ResolveDevice;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/home");
+    /// for device in path.resolve_physical_devices()? {
+    ///     println!("Physical device: {}", device.display());
+    /// }
+    /// # Ok::<(), blkpath::DeviceResolveError>(())
+    /// ```
+    fn resolve_physical_devices(&self) -> Result<Vec<PathBuf>, DeviceResolveError>;
+
+    /// Resolves the device path together with its stable udev-style
+    /// identifiers and basic hardware attributes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if:
+    /// - The file/path cannot be accessed
+    /// - The device information cannot be retrieved
+    /// - The device cannot be mapped to a block device path
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use blkpath::ResolveDevice;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/home");
+    /// let info = path.resolve_device_info()?;
+    /// println!("Device: {}", info.path.display());
+    /// # Ok::<(), blkpath::DeviceResolveError>(())
+    /// ```
+    fn resolve_device_info(&self) -> Result<DeviceInfo, DeviceResolveError>;
+
+    /// Classifies the resolved device and reports its size and rotational
+    /// attribute.
+    ///
+    /// If the file/path's device has no backing block device (e.g. tmpfs or
+    /// procfs), this reports [`DeviceKind::Virtual`] with a zero size rather
+    /// than erroring.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if the file/path cannot be accessed or
+    /// the device information cannot be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use blkpath::ResolveDevice;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/home");
+    /// let attrs = path.resolve_device_attributes()?;
+    /// println!("{:?}, {} bytes", attrs.kind, attrs.size_bytes);
+    /// # Ok::<(), blkpath::DeviceResolveError>(())
+    /// ```
+    fn resolve_device_attributes(&self) -> Result<DeviceAttributes, DeviceResolveError>;
 }
 
 impl ResolveDevice for Path {
@@ -130,12 +257,72 @@ impl ResolveDevice for Path {
 
         resolve_device_from_dev(major, minor)
     }
+
+    fn resolve_disk(&self) -> Result<PathBuf, DeviceResolveError> {
+        let metadata = fs::metadata(self)
+            .map_err(|e| DeviceResolveError::MetadataError(format!("{}: {}", self.display(), e)))?;
+
+        let dev = metadata.dev();
+        let major = major(dev);
+        let minor = minor(dev);
+
+        resolve_disk_from_dev(major, minor)
+    }
+
+    fn resolve_physical_devices(&self) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        let metadata = fs::metadata(self)
+            .map_err(|e| DeviceResolveError::MetadataError(format!("{}: {}", self.display(), e)))?;
+
+        let dev = metadata.dev();
+        let major = major(dev);
+        let minor = minor(dev);
+
+        resolve_physical_devices_from_dev(major, minor)
+    }
+
+    fn resolve_device_info(&self) -> Result<DeviceInfo, DeviceResolveError> {
+        let metadata = fs::metadata(self)
+            .map_err(|e| DeviceResolveError::MetadataError(format!("{}: {}", self.display(), e)))?;
+
+        let dev = metadata.dev();
+        let major = major(dev);
+        let minor = minor(dev);
+
+        resolve_device_info_from_dev(major, minor)
+    }
+
+    fn resolve_device_attributes(&self) -> Result<DeviceAttributes, DeviceResolveError> {
+        let metadata = fs::metadata(self)
+            .map_err(|e| DeviceResolveError::MetadataError(format!("{}: {}", self.display(), e)))?;
+
+        let dev = metadata.dev();
+        let major = major(dev);
+        let minor = minor(dev);
+
+        resolve_device_attributes_from_dev(major, minor)
+    }
 }
 
 impl ResolveDevice for PathBuf {
     fn resolve_device(&self) -> Result<PathBuf, DeviceResolveError> {
         self.as_path().resolve_device()
     }
+
+    fn resolve_disk(&self) -> Result<PathBuf, DeviceResolveError> {
+        self.as_path().resolve_disk()
+    }
+
+    fn resolve_physical_devices(&self) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        self.as_path().resolve_physical_devices()
+    }
+
+    fn resolve_device_info(&self) -> Result<DeviceInfo, DeviceResolveError> {
+        self.as_path().resolve_device_info()
+    }
+
+    fn resolve_device_attributes(&self) -> Result<DeviceAttributes, DeviceResolveError> {
+        self.as_path().resolve_device_attributes()
+    }
 }
 
 impl ResolveDevice for File {
@@ -144,26 +331,139 @@ impl ResolveDevice for File {
         let (major, minor) = get_dev_from_fd(fd)?;
         resolve_device_from_dev(major, minor)
     }
+
+    fn resolve_disk(&self) -> Result<PathBuf, DeviceResolveError> {
+        let fd = self.as_raw_fd();
+        let (major, minor) = get_dev_from_fd(fd)?;
+        resolve_disk_from_dev(major, minor)
+    }
+
+    fn resolve_physical_devices(&self) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        let fd = self.as_raw_fd();
+        let (major, minor) = get_dev_from_fd(fd)?;
+        resolve_physical_devices_from_dev(major, minor)
+    }
+
+    fn resolve_device_info(&self) -> Result<DeviceInfo, DeviceResolveError> {
+        let fd = self.as_raw_fd();
+        let (major, minor) = get_dev_from_fd(fd)?;
+        resolve_device_info_from_dev(major, minor)
+    }
+
+    fn resolve_device_attributes(&self) -> Result<DeviceAttributes, DeviceResolveError> {
+        let fd = self.as_raw_fd();
+        let (major, minor) = get_dev_from_fd(fd)?;
+        resolve_device_attributes_from_dev(major, minor)
+    }
 }
 
 impl ResolveDevice for &File {
     fn resolve_device(&self) -> Result<PathBuf, DeviceResolveError> {
         (*self).resolve_device()
     }
+
+    fn resolve_disk(&self) -> Result<PathBuf, DeviceResolveError> {
+        (*self).resolve_disk()
+    }
+
+    fn resolve_physical_devices(&self) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        (*self).resolve_physical_devices()
+    }
+
+    fn resolve_device_info(&self) -> Result<DeviceInfo, DeviceResolveError> {
+        (*self).resolve_device_info()
+    }
+
+    fn resolve_device_attributes(&self) -> Result<DeviceAttributes, DeviceResolveError> {
+        (*self).resolve_device_attributes()
+    }
 }
 
 /// Extracts the major device number from a device ID.
+///
+/// # Example
+///
+/// ```rust
+/// use blkpath::major;
+///
+/// assert_eq!(major(0x0801), 8);
+/// ```
 #[inline]
-fn major(dev: u64) -> u32 {
+pub fn major(dev: u64) -> u32 {
     ((dev >> 8) & 0xfff) as u32 | (((dev >> 32) & !0xfff) as u32)
 }
 
 /// Extracts the minor device number from a device ID.
+///
+/// # Example
+///
+/// ```rust
+/// use blkpath::minor;
+///
+/// assert_eq!(minor(0x0801), 1);
+/// ```
 #[inline]
-fn minor(dev: u64) -> u32 {
+pub fn minor(dev: u64) -> u32 {
     (dev & 0xff) as u32 | (((dev >> 12) & !0xff) as u32)
 }
 
+/// Largest valid major device number (12-bit field).
+const MAJOR_MAX: u32 = (1 << 12) - 1;
+
+/// Largest valid minor device number (20-bit field).
+const MINOR_MAX: u32 = (1 << 20) - 1;
+
+/// Combines a major and minor device number into a `dev_t`-style device ID.
+///
+/// This is the inverse of [`major`]/[`minor`] and does not validate its
+/// inputs; use [`parse_devnum`] when parsing untrusted input.
+///
+/// # Example
+///
+/// ```rust
+/// use blkpath::{major, makedev, minor};
+///
+/// let dev = makedev(8, 1);
+/// assert_eq!(major(dev), 8);
+/// assert_eq!(minor(dev), 1);
+/// ```
+#[inline]
+pub fn makedev(major: u32, minor: u32) -> u64 {
+    (minor as u64 & 0xff)
+        | ((major as u64 & 0xfff) << 8)
+        | ((minor as u64 & !0xff) << 12)
+        | ((major as u64 & !0xfff) << 32)
+}
+
+/// Parses a `"major:minor"` device number string, validating both fields
+/// against the kernel's major (12-bit) and minor (20-bit) ranges.
+///
+/// # Errors
+///
+/// Returns `DeviceResolveError::InvalidDevnum` if `devnum` isn't of the form
+/// `"major:minor"`, or if either field overflows its valid range.
+///
+/// # Example
+///
+/// ```rust
+/// use blkpath::parse_devnum;
+///
+/// assert_eq!(parse_devnum("8:1")?, (8, 1));
+/// assert!(parse_devnum("4096:0").is_err());
+/// # Ok::<(), blkpath::DeviceResolveError>(())
+/// ```
+pub fn parse_devnum(devnum: &str) -> Result<(u32, u32), DeviceResolveError> {
+    let (major, minor) = parse_dev_field(devnum)
+        .filter(|_| devnum.matches(':').count() == 1)
+        .ok_or_else(|| DeviceResolveError::InvalidDevnum(devnum.to_string()))?;
+
+    if major > MAJOR_MAX || minor > MINOR_MAX {
+        return Err(DeviceResolveError::InvalidDevnum(devnum.to_string()));
+    }
+
+    Ok((major, minor))
+}
+
 /// Gets the device major:minor from a file descriptor using fstat.
 fn get_dev_from_fd(fd: i32) -> Result<(u32, u32), DeviceResolveError> {
     let mut stat_buf: libc::stat = unsafe { std::mem::zeroed() };
@@ -179,145 +479,577 @@ fn get_dev_from_fd(fd: i32) -> Result<(u32, u32), DeviceResolveError> {
     Ok((major(dev), minor(dev)))
 }
 
-/// Resolves a device path from major:minor numbers.
-///
-/// This function tries multiple resolution strategies:
-/// 1. First, try to resolve via `/sys/dev/block/{major}:{minor}`
-/// 2. If that fails, fall back to parsing `/proc/self/mountinfo`
+/// Resolves a device path from major:minor numbers using the default roots.
 fn resolve_device_from_dev(major: u32, minor: u32) -> Result<PathBuf, DeviceResolveError> {
-    // Try sysfs first
-    if let Some(path) = resolve_via_sysfs(major, minor) {
-        return Ok(path);
-    }
+    DeviceResolver::default().resolve_mm(major, minor)
+}
 
-    // Fall back to mountinfo
-    if let Some(path) = resolve_via_mountinfo(major, minor)? {
-        return Ok(path);
-    }
+/// Resolves the containing whole-disk device path from major:minor numbers
+/// using the default roots.
+fn resolve_disk_from_dev(major: u32, minor: u32) -> Result<PathBuf, DeviceResolveError> {
+    DeviceResolver::default().resolve_disk_mm(major, minor)
+}
 
-    Err(DeviceResolveError::DeviceNotFound { major, minor })
+/// Resolves the leaf physical devices from major:minor numbers using the
+/// default roots.
+fn resolve_physical_devices_from_dev(
+    major: u32,
+    minor: u32,
+) -> Result<Vec<PathBuf>, DeviceResolveError> {
+    DeviceResolver::default().resolve_physical_devices_mm(major, minor)
 }
 
-/// Resolves a device path via the sysfs interface.
+/// Resolves device info from major:minor numbers using the default roots.
+fn resolve_device_info_from_dev(major: u32, minor: u32) -> Result<DeviceInfo, DeviceResolveError> {
+    DeviceResolver::default().resolve_device_info_mm(major, minor)
+}
+
+/// Resolves device attributes from major:minor numbers using the default
+/// roots.
+fn resolve_device_attributes_from_dev(
+    major: u32,
+    minor: u32,
+) -> Result<DeviceAttributes, DeviceResolveError> {
+    DeviceResolver::default().resolve_device_attributes_mm(major, minor)
+}
+
+/// A coarse classification of a resolved block device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    /// A whole disk, e.g. `/dev/sda` or `/dev/nvme0n1`.
+    WholeDisk,
+    /// A partition of a whole disk, e.g. `/dev/sda1`.
+    Partition,
+    /// A device-mapper device, including LVM logical volumes.
+    DeviceMapper,
+    /// An MD (software RAID) array.
+    Md,
+    /// A loopback device.
+    Loop,
+    /// No backing block device was found (e.g. tmpfs, procfs).
+    Virtual,
+}
+
+/// Basic storage attributes of a resolved device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceAttributes {
+    /// The device's coarse classification.
+    pub kind: DeviceKind,
+    /// Size in bytes of the device, or of its containing whole disk when the
+    /// device is a [`DeviceKind::Partition`]; 0 for a [`DeviceKind::Virtual`]
+    /// device.
+    pub size_bytes: u64,
+    /// Whether the device is rotational (spinning) media.
+    pub rotational: bool,
+}
+
+/// The resolved device path together with its stable udev-style identifiers
+/// and basic hardware attributes.
+///
+/// The symlink fields are populated by scanning `/dev/disk/by-*` for entries
+/// that canonicalize to [`DeviceInfo::path`]; the attribute fields come from
+/// `/sys/block/<disk>/device/{model,serial,wwid}` of the containing whole
+/// disk. Any of them may be empty/`None` if the kernel or udev rules don't
+/// expose that particular identifier for the device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// The resolved `/dev` path, e.g. `/dev/sda1`.
+    pub path: PathBuf,
+    /// Matching symlinks under `/dev/disk/by-id`.
+    pub by_id: Vec<PathBuf>,
+    /// Matching symlinks under `/dev/disk/by-uuid`.
+    pub by_uuid: Vec<PathBuf>,
+    /// Matching symlinks under `/dev/disk/by-partuuid`.
+    pub by_partuuid: Vec<PathBuf>,
+    /// Matching symlinks under `/dev/disk/by-path`.
+    pub by_path: Vec<PathBuf>,
+    /// Device model string, e.g. `"Samsung SSD 970 EVO"`.
+    pub model: Option<String>,
+    /// Device serial number.
+    pub serial: Option<String>,
+    /// Device WWID (world-wide identifier).
+    pub wwid: Option<String>,
+}
+
+/// A reusable, configurable device resolver.
+///
+/// `DeviceResolver` holds the filesystem roots (`/sys`, `/dev`, and
+/// `/proc/self/mountinfo` by default) used during resolution. Overriding
+/// them lets the sysfs/mountinfo logic be exercised against fixture
+/// directories in tests instead of the live system.
+///
+/// Mountinfo is parsed at most once per resolver: the first lookup that
+/// falls back to mountinfo builds a `major:minor -> path` map and caches it,
+/// so resolving many paths through the same resolver doesn't re-read and
+/// re-scan the file for each one.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use blkpath::DeviceResolver;
 ///
-/// Looks up `/sys/dev/block/{major}:{minor}` and follows the symlink to find
-/// the actual device name.
-fn resolve_via_sysfs(major: u32, minor: u32) -> Option<PathBuf> {
-    let sysfs_path = format!("/sys/dev/block/{}:{}", major, minor);
-    let sysfs_path = Path::new(&sysfs_path);
+/// let resolver = DeviceResolver::new().with_sysfs_root("/sys");
+/// let device = resolver.resolve(0x0801)?;
+/// println!("Device: {}", device.display());
+/// # Ok::<(), blkpath::DeviceResolveError>(())
+/// ```
+pub struct DeviceResolver {
+    sysfs_root: PathBuf,
+    dev_root: PathBuf,
+    mountinfo_path: PathBuf,
+    mountinfo_cache: OnceLock<HashMap<(u32, u32), PathBuf>>,
+}
+
+impl Default for DeviceResolver {
+    fn default() -> Self {
+        Self {
+            sysfs_root: PathBuf::from("/sys"),
+            dev_root: PathBuf::from("/dev"),
+            mountinfo_path: PathBuf::from("/proc/self/mountinfo"),
+            mountinfo_cache: OnceLock::new(),
+        }
+    }
+}
 
-    if !sysfs_path.exists() {
-        return None;
+impl DeviceResolver {
+    /// Creates a resolver using the default roots (`/sys`, `/dev`,
+    /// `/proc/self/mountinfo`).
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Read the symlink target to get the device name
-    let target = fs::read_link(sysfs_path).ok()?;
+    /// Overrides the sysfs root (default `/sys`).
+    pub fn with_sysfs_root(mut self, sysfs_root: impl Into<PathBuf>) -> Self {
+        self.sysfs_root = sysfs_root.into();
+        self
+    }
 
-    // Extract device name from path like "../../block/sda/sda1"
-    let device_name = target.file_name()?.to_str()?;
+    /// Overrides the `/dev` root (default `/dev`).
+    pub fn with_dev_root(mut self, dev_root: impl Into<PathBuf>) -> Self {
+        self.dev_root = dev_root.into();
+        self
+    }
 
-    let dev_path = PathBuf::from(format!("/dev/{}", device_name));
-    if dev_path.exists() {
-        return Some(dev_path);
+    /// Overrides the mountinfo path (default `/proc/self/mountinfo`).
+    pub fn with_mountinfo_path(mut self, mountinfo_path: impl Into<PathBuf>) -> Self {
+        self.mountinfo_path = mountinfo_path.into();
+        self
     }
 
-    // Try to find the device in /dev recursively
-    find_device_in_dev(device_name)
-}
+    /// Resolves the underlying block device path for a `dev_t` device ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if the device cannot be mapped to a
+    /// block device path via sysfs or mountinfo.
+    pub fn resolve(&self, dev: u64) -> Result<PathBuf, DeviceResolveError> {
+        self.resolve_mm(major(dev), minor(dev))
+    }
+
+    /// Resolves the containing whole-disk device path for a `dev_t` device ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if the parent disk cannot be mapped to
+    /// a block device path.
+    pub fn resolve_disk(&self, dev: u64) -> Result<PathBuf, DeviceResolveError> {
+        self.resolve_disk_mm(major(dev), minor(dev))
+    }
+
+    /// Resolves the leaf physical devices backing a `dev_t` device ID,
+    /// descending any device-mapper/LVM/mdraid `slaves/` stacking in sysfs.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if the device cannot be found in
+    /// sysfs.
+    pub fn resolve_physical_devices(&self, dev: u64) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        self.resolve_physical_devices_mm(major(dev), minor(dev))
+    }
+
+    /// Resolves the device path and enriches it with stable udev-style
+    /// identifiers and basic hardware attributes for a `dev_t` device ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeviceResolveError` if the device cannot be mapped to a
+    /// block device path.
+    pub fn resolve_device_info(&self, dev: u64) -> Result<DeviceInfo, DeviceResolveError> {
+        self.resolve_device_info_mm(major(dev), minor(dev))
+    }
+
+    /// Classifies the device and reports its size and rotational attribute
+    /// for a `dev_t` device ID.
+    ///
+    /// Reports [`DeviceKind::Virtual`] with a zero size if the device has no
+    /// backing block device in sysfs, rather than erroring.
+    pub fn resolve_device_attributes(
+        &self,
+        dev: u64,
+    ) -> Result<DeviceAttributes, DeviceResolveError> {
+        self.resolve_device_attributes_mm(major(dev), minor(dev))
+    }
+
+    /// Resolves a device path from major:minor numbers.
+    ///
+    /// This tries multiple resolution strategies:
+    /// 1. First, try to resolve via `{sysfs_root}/dev/block/{major}:{minor}`
+    /// 2. If that fails, fall back to parsing `mountinfo_path`
+    fn resolve_mm(&self, major: u32, minor: u32) -> Result<PathBuf, DeviceResolveError> {
+        if let Some(path) = self.resolve_via_sysfs(major, minor) {
+            return Ok(path);
+        }
+
+        if let Some(path) = self.resolve_via_mountinfo(major, minor)? {
+            return Ok(path);
+        }
+
+        Err(DeviceResolveError::DeviceNotFound { major, minor })
+    }
+
+    /// Resolves the containing whole-disk device path from major:minor numbers.
+    ///
+    /// This mirrors `resolve_mm`, but walks up from a partition to its parent
+    /// whole disk via sysfs: `{sysfs_root}/dev/block/{major}:{minor}` is
+    /// followed to a directory like `.../block/sda/sda1`; if that directory
+    /// contains a `partition` file, the parent disk is the parent directory
+    /// (`.../block/sda`), otherwise the device is already a whole disk. The
+    /// parent's `dev` file must hold a valid major:minor, and the parent's
+    /// name is then looked up through `find_device_in_dev` to get back to a
+    /// `dev_root` path.
+    fn resolve_disk_mm(&self, major: u32, minor: u32) -> Result<PathBuf, DeviceResolveError> {
+        self.resolve_disk_via_sysfs(major, minor)
+            .ok_or(DeviceResolveError::SysfsResolutionFailed { major, minor })
+    }
+
+    /// Resolves the parent whole-disk device via the sysfs interface.
+    ///
+    /// See `resolve_disk_mm` for the strategy.
+    fn resolve_disk_via_sysfs(&self, major: u32, minor: u32) -> Option<PathBuf> {
+        let disk_dir = self.sysfs_disk_dir(major, minor)?;
+
+        // Reading the parent's `dev` file confirms it really is a block
+        // device before we round-trip its name through `find_device_in_dev`.
+        let dev_field = fs::read_to_string(disk_dir.join("dev")).ok()?;
+        parse_dev_field(dev_field.trim())?;
+        let disk_name = disk_dir.file_name()?.to_str()?;
+
+        self.find_device_in_dev(disk_name)
+    }
+
+    /// Resolves the sysfs directory of the whole disk containing the device
+    /// at major:minor, e.g. `.../block/sda` for either `sda` or `sda1`.
+    fn sysfs_disk_dir(&self, major: u32, minor: u32) -> Option<PathBuf> {
+        let device_dir = self.sysfs_device_dir(major, minor)?;
+
+        if device_dir.join("partition").exists() {
+            Some(device_dir.parent()?.to_path_buf())
+        } else {
+            Some(device_dir)
+        }
+    }
+
+    /// Resolves the sysfs device directory for the device at major:minor
+    /// without collapsing a partition to its parent disk, e.g.
+    /// `.../block/sda/sda1` for a partition or `.../block/sda` for a disk.
+    fn sysfs_device_dir(&self, major: u32, minor: u32) -> Option<PathBuf> {
+        let sysfs_path = self
+            .sysfs_root
+            .join(format!("dev/block/{}:{}", major, minor));
 
-/// Searches for a device with the given name in /dev.
-fn find_device_in_dev(device_name: &str) -> Option<PathBuf> {
-    // Common locations to check
-    let paths_to_check = [
-        format!("/dev/{}", device_name),
-        format!("/dev/mapper/{}", device_name),
-        format!("/dev/disk/by-id/{}", device_name),
-    ];
-
-    for path_str in &paths_to_check {
-        let path = PathBuf::from(path_str);
-        if path.exists() {
-            return Some(path);
+        // Resolve the symlink all the way to the real sysfs device directory,
+        // e.g. ".../devices/pci.../block/sda/sda1".
+        fs::canonicalize(&sysfs_path).ok()
+    }
+
+    /// Resolves a device path via the sysfs interface.
+    ///
+    /// Looks up `{sysfs_root}/dev/block/{major}:{minor}` and follows the
+    /// symlink to find the actual device name.
+    fn resolve_via_sysfs(&self, major: u32, minor: u32) -> Option<PathBuf> {
+        let device_name = self.sysfs_device_name(major, minor)?;
+
+        let dev_path = self.dev_root.join(&device_name);
+        if dev_path.exists() {
+            return Some(dev_path);
         }
+
+        // Try to find the device in dev_root recursively
+        self.find_device_in_dev(&device_name)
     }
 
-    // If still not found, try to find in /dev directory
-    if let Ok(entries) = fs::read_dir("/dev") {
-        for entry in entries.flatten() {
-            if entry.file_name().to_string_lossy() == device_name {
-                return Some(entry.path());
+    /// Looks up `{sysfs_root}/dev/block/{major}:{minor}` and returns the
+    /// device name from the symlink target, e.g. "sda1" from
+    /// "../../block/sda/sda1".
+    fn sysfs_device_name(&self, major: u32, minor: u32) -> Option<String> {
+        let sysfs_path = self
+            .sysfs_root
+            .join(format!("dev/block/{}:{}", major, minor));
+
+        if !sysfs_path.exists() {
+            return None;
+        }
+
+        let target = fs::read_link(&sysfs_path).ok()?;
+        target.file_name()?.to_str().map(str::to_owned)
+    }
+
+    /// Resolves the leaf physical devices backing the device at
+    /// major:minor, descending any device-mapper/LVM/mdraid `slaves/`
+    /// stacking in sysfs.
+    fn resolve_physical_devices_mm(
+        &self,
+        major: u32,
+        minor: u32,
+    ) -> Result<Vec<PathBuf>, DeviceResolveError> {
+        let device_name = self
+            .sysfs_device_name(major, minor)
+            .ok_or(DeviceResolveError::SysfsResolutionFailed { major, minor })?;
+
+        let mut leaves = Vec::new();
+        let mut seen = HashSet::new();
+        self.collect_physical_devices(&device_name, &mut leaves, &mut seen);
+        Ok(leaves)
+    }
+
+    /// Depth-first walks `{sysfs_root}/block/{name}/slaves/`, pushing the
+    /// resolved path of every leaf device (one with no `slaves/` entries,
+    /// or none at all) onto `leaves`. `seen` deduplicates by device name.
+    fn collect_physical_devices(
+        &self,
+        name: &str,
+        leaves: &mut Vec<PathBuf>,
+        seen: &mut HashSet<String>,
+    ) {
+        if !seen.insert(name.to_string()) {
+            return;
+        }
+
+        let slaves_dir = self.sysfs_root.join("block").join(name).join("slaves");
+        let slaves: Vec<String> = fs::read_dir(&slaves_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if slaves.is_empty() {
+            if let Some(leaf) = self.find_device_in_dev(name) {
+                leaves.push(leaf);
             }
+            return;
+        }
+
+        for slave in slaves {
+            self.collect_physical_devices(&slave, leaves, seen);
         }
     }
 
-    None
-}
+    /// Resolves the device path from major:minor numbers and enriches it
+    /// with stable udev-style identifiers and basic hardware attributes.
+    fn resolve_device_info_mm(
+        &self,
+        major: u32,
+        minor: u32,
+    ) -> Result<DeviceInfo, DeviceResolveError> {
+        let path = self.resolve_mm(major, minor)?;
 
-/// Resolves a device path by parsing /proc/self/mountinfo.
-///
-/// The mountinfo file format is documented in proc(5).
-/// Each line contains fields separated by spaces:
-/// - mount ID
-/// - parent ID
-/// - major:minor
-/// - root
-/// - mount point
-/// - mount options
-/// - optional fields (terminated by " - ")
-/// - filesystem type
-/// - mount source
-/// - super options
-fn resolve_via_mountinfo(major: u32, minor: u32) -> Result<Option<PathBuf>, DeviceResolveError> {
-    let mountinfo_path = Path::new("/proc/self/mountinfo");
-    if !mountinfo_path.exists() {
-        return Ok(None);
-    }
-
-    let file = File::open(mountinfo_path)?;
-    let reader = BufReader::new(file);
-
-    for line in reader.lines() {
-        let line = line?;
-        if let Some(device) = parse_mountinfo_line(&line, major, minor) {
-            return Ok(Some(device));
+        let mut info = DeviceInfo {
+            path: path.clone(),
+            by_id: self.find_matching_symlinks("by-id", &path),
+            by_uuid: self.find_matching_symlinks("by-uuid", &path),
+            by_partuuid: self.find_matching_symlinks("by-partuuid", &path),
+            by_path: self.find_matching_symlinks("by-path", &path),
+            ..Default::default()
+        };
+
+        if let Some(disk_dir) = self.sysfs_disk_dir(major, minor) {
+            let device_dir = disk_dir.join("device");
+            info.model = read_sysfs_attr(&device_dir.join("model"));
+            info.serial = read_sysfs_attr(&device_dir.join("serial"));
+            info.wwid = read_sysfs_attr(&device_dir.join("wwid"));
         }
+
+        Ok(info)
     }
 
-    Ok(None)
-}
+    /// Classifies the device at major:minor and reports its size and
+    /// rotational attribute.
+    ///
+    /// See `resolve_device_attributes` for the `Virtual` fallback.
+    fn resolve_device_attributes_mm(
+        &self,
+        major: u32,
+        minor: u32,
+    ) -> Result<DeviceAttributes, DeviceResolveError> {
+        let Some(device_dir) = self.sysfs_device_dir(major, minor) else {
+            return Ok(DeviceAttributes {
+                kind: DeviceKind::Virtual,
+                size_bytes: 0,
+                rotational: false,
+            });
+        };
+
+        let kind = classify_device_kind(&device_dir);
+        let disk_dir = if kind == DeviceKind::Partition {
+            device_dir
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or(device_dir)
+        } else {
+            device_dir
+        };
+
+        let size_bytes = read_sysfs_attr(&disk_dir.join("size"))
+            .and_then(|size| size.parse::<u64>().ok())
+            .map(|sectors| sectors * 512)
+            .unwrap_or(0);
+        let rotational =
+            read_sysfs_attr(&disk_dir.join("queue/rotational")).as_deref() == Some("1");
 
-/// Parses a single line from mountinfo and returns the device path if it matches.
-fn parse_mountinfo_line(line: &str, target_major: u32, target_minor: u32) -> Option<PathBuf> {
-    let fields: Vec<&str> = line.split_whitespace().collect();
-    if fields.len() < 10 {
-        return None;
+        Ok(DeviceAttributes {
+            kind,
+            size_bytes,
+            rotational,
+        })
     }
 
-    // Field 3 is major:minor
-    let dev_field = fields.get(2)?;
-    let (major, minor) = parse_dev_field(dev_field)?;
+    /// Scans `{dev_root}/disk/{dir}` and returns every entry whose
+    /// canonicalized target matches `target`.
+    fn find_matching_symlinks(&self, dir: &str, target: &Path) -> Vec<PathBuf> {
+        let by_dir = self.dev_root.join("disk").join(dir);
 
-    if major != target_major || minor != target_minor {
-        return None;
+        let Ok(entries) = fs::read_dir(&by_dir) else {
+            return Vec::new();
+        };
+
+        let Ok(canonical_target) = fs::canonicalize(target) else {
+            return Vec::new();
+        };
+
+        let mut matches: Vec<PathBuf> = entries
+            .flatten()
+            .filter(|entry| fs::canonicalize(entry.path()).ok().as_ref() == Some(&canonical_target))
+            .map(|entry| entry.path())
+            .collect();
+        matches.sort();
+        matches
     }
 
-    // Find the separator " - " to get the mount source
-    let separator_idx = fields.iter().position(|&f| f == "-")?;
+    /// Searches for a device with the given name under `dev_root`.
+    fn find_device_in_dev(&self, device_name: &str) -> Option<PathBuf> {
+        // Common locations to check
+        let paths_to_check = [
+            self.dev_root.join(device_name),
+            self.dev_root.join("mapper").join(device_name),
+            self.dev_root.join("disk/by-id").join(device_name),
+        ];
+
+        for path in &paths_to_check {
+            if path.exists() {
+                return Some(path.clone());
+            }
+        }
+
+        // If still not found, try to find in dev_root directly
+        if let Ok(entries) = fs::read_dir(&self.dev_root) {
+            for entry in entries.flatten() {
+                if entry.file_name().to_string_lossy() == device_name {
+                    return Some(entry.path());
+                }
+            }
+        }
 
-    // Mount source is 2 fields after the separator
-    let mount_source = fields.get(separator_idx + 2)?;
+        None
+    }
 
-    if mount_source.starts_with('/') {
-        return Some(PathBuf::from(mount_source));
+    /// Resolves a device path by looking it up in the cached mountinfo map,
+    /// building the cache from `mountinfo_path` on first use.
+    ///
+    /// The mountinfo file format is documented in proc(5). Each line
+    /// contains fields separated by spaces:
+    /// - mount ID
+    /// - parent ID
+    /// - major:minor
+    /// - root
+    /// - mount point
+    /// - mount options
+    /// - optional fields (terminated by " - ")
+    /// - filesystem type
+    /// - mount source
+    /// - super options
+    fn resolve_via_mountinfo(
+        &self,
+        major: u32,
+        minor: u32,
+    ) -> Result<Option<PathBuf>, DeviceResolveError> {
+        Ok(self.mountinfo_map()?.get(&(major, minor)).cloned())
     }
 
-    // For non-path sources (like "tmpfs", "proc", etc.), try /dev
-    let dev_path = PathBuf::from(format!("/dev/{}", mount_source));
-    if dev_path.exists() {
-        return Some(dev_path);
+    /// Returns the cached `major:minor -> path` map, parsing `mountinfo_path`
+    /// the first time it's needed.
+    fn mountinfo_map(&self) -> Result<&HashMap<(u32, u32), PathBuf>, DeviceResolveError> {
+        if let Some(map) = self.mountinfo_cache.get() {
+            return Ok(map);
+        }
+
+        let map = self.parse_mountinfo()?;
+        Ok(self.mountinfo_cache.get_or_init(|| map))
     }
 
-    None
+    /// Parses `mountinfo_path` into a `major:minor -> path` map.
+    fn parse_mountinfo(&self) -> Result<HashMap<(u32, u32), PathBuf>, DeviceResolveError> {
+        let mut map = HashMap::new();
+
+        if !self.mountinfo_path.exists() {
+            return Ok(map);
+        }
+
+        let file = File::open(&self.mountinfo_path)?;
+        let reader = BufReader::new(file);
+
+        for line in reader.lines() {
+            let line = line?;
+            if let Some((dev, device)) = self.parse_mountinfo_line(&line) {
+                map.entry(dev).or_insert(device);
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Parses a single line from mountinfo, returning its major:minor and
+    /// resolved device path.
+    fn parse_mountinfo_line(&self, line: &str) -> Option<((u32, u32), PathBuf)> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 {
+            return None;
+        }
+
+        // Field 3 is major:minor
+        let dev_field = fields.get(2)?;
+        let dev = parse_dev_field(dev_field)?;
+
+        // Find the separator " - " to get the mount source
+        let separator_idx = fields.iter().position(|&f| f == "-")?;
+
+        // Mount source is 2 fields after the separator
+        let mount_source = fields.get(separator_idx + 2)?;
+
+        if mount_source.starts_with('/') {
+            return Some((dev, PathBuf::from(mount_source)));
+        }
+
+        // For non-path sources (like "tmpfs", "proc", etc.), try dev_root
+        let dev_path = self.dev_root.join(mount_source);
+        if dev_path.exists() {
+            return Some((dev, dev_path));
+        }
+
+        None
+    }
 }
 
 /// Parses a "major:minor" string into (u32, u32).
@@ -328,6 +1060,34 @@ fn parse_dev_field(field: &str) -> Option<(u32, u32)> {
     Some((major, minor))
 }
 
+/// Reads a sysfs attribute file, returning its trimmed contents if present
+/// and non-empty.
+fn read_sysfs_attr(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Classifies a sysfs device directory using the marker files the kernel
+/// exposes for each stacking type: `partition`, `dm/`, `md/`, and `loop/`.
+fn classify_device_kind(device_dir: &Path) -> DeviceKind {
+    if device_dir.join("partition").exists() {
+        DeviceKind::Partition
+    } else if device_dir.join("dm").is_dir() {
+        DeviceKind::DeviceMapper
+    } else if device_dir.join("md").is_dir() {
+        DeviceKind::Md
+    } else if device_dir.join("loop").is_dir() {
+        DeviceKind::Loop
+    } else {
+        DeviceKind::WholeDisk
+    }
+}
+
 /// Convenience function to resolve the device for a path.
 ///
 /// This is a free function that provides the same functionality as the
@@ -388,6 +1148,26 @@ mod tests {
         assert_eq!(parse_dev_field(":1"), None);
     }
 
+    #[test]
+    fn test_makedev_roundtrip() {
+        let dev = makedev(8, 1);
+        assert_eq!(major(dev), 8);
+        assert_eq!(minor(dev), 1);
+
+        let dev = makedev(253, 0);
+        assert_eq!(major(dev), 253);
+        assert_eq!(minor(dev), 0);
+    }
+
+    #[test]
+    fn test_parse_devnum() {
+        assert_eq!(parse_devnum("8:1").unwrap(), (8, 1));
+        assert_eq!(parse_devnum("4095:1048575").unwrap(), (4095, 1048575));
+        assert!(parse_devnum("4096:0").is_err());
+        assert!(parse_devnum("0:1048576").is_err());
+        assert!(parse_devnum("invalid").is_err());
+    }
+
     #[test]
     fn test_resolve_device_for_root() {
         // Root filesystem should always be resolvable
@@ -433,24 +1213,80 @@ mod tests {
 
     #[test]
     fn test_parse_mountinfo_line() {
+        let resolver = DeviceResolver::new();
+
         // Example mountinfo line
         let line = "29 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw";
-        let result = parse_mountinfo_line(line, 8, 1);
-        assert_eq!(result, Some(PathBuf::from("/dev/sda1")));
-
-        // Non-matching line
-        let result = parse_mountinfo_line(line, 9, 2);
-        assert!(result.is_none());
+        let result = resolver.parse_mountinfo_line(line);
+        assert_eq!(result, Some(((8, 1), PathBuf::from("/dev/sda1"))));
     }
 
     #[test]
     fn test_parse_mountinfo_line_with_special_fs() {
         // tmpfs doesn't have a real device
+        let resolver = DeviceResolver::new();
         let line = "22 20 0:21 / /dev/shm rw,nosuid,nodev shared:3 - tmpfs tmpfs rw";
-        let result = parse_mountinfo_line(line, 0, 21);
+        let result = resolver.parse_mountinfo_line(line);
         // tmpfs doesn't start with /, so it returns None or tries /dev/tmpfs
         // This should return None since /dev/tmpfs doesn't exist
-        assert!(result.is_none() || result == Some(PathBuf::from("/dev/tmpfs")));
+        assert!(result.is_none() || result == Some(((0, 21), PathBuf::from("/dev/tmpfs"))));
+    }
+
+    #[test]
+    fn test_device_resolver_mountinfo_cache_with_fixtures() {
+        let temp_dir = TempDir::new().unwrap();
+        let mountinfo_path = temp_dir.path().join("mountinfo");
+        fs::write(
+            &mountinfo_path,
+            "29 1 8:1 / / rw,relatime shared:1 - ext4 /dev/sda1 rw\n\
+             30 29 8:2 / /home rw,relatime shared:2 - ext4 /dev/sda2 rw\n",
+        )
+        .unwrap();
+
+        let resolver = DeviceResolver::new()
+            .with_sysfs_root(temp_dir.path().join("sys"))
+            .with_dev_root(temp_dir.path().join("dev"))
+            .with_mountinfo_path(mountinfo_path);
+
+        // Two lookups against the same resolver should hit the same cached map.
+        assert_eq!(
+            resolver.resolve_mm(8, 1).unwrap(),
+            PathBuf::from("/dev/sda1")
+        );
+        assert_eq!(
+            resolver.resolve_mm(8, 2).unwrap(),
+            PathBuf::from("/dev/sda2")
+        );
+        assert!(resolver.mountinfo_cache.get().is_some());
+        assert!(resolver.resolve_mm(8, 3).is_err());
+    }
+
+    #[test]
+    fn test_resolve_physical_devices_through_dm_stack() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_root = temp_dir.path().join("sys");
+        let dev_root = temp_dir.path().join("dev");
+
+        // /sys/dev/block/253:0 -> ../../block/dm-0, backed by sda and sdb.
+        fs::create_dir_all(sysfs_root.join("dev/block")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/dm-0/slaves")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sda")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sdb")).unwrap();
+        std::os::unix::fs::symlink("../../block/dm-0", sysfs_root.join("dev/block/253:0")).unwrap();
+        std::os::unix::fs::symlink("../../sda", sysfs_root.join("block/dm-0/slaves/sda")).unwrap();
+        std::os::unix::fs::symlink("../../sdb", sysfs_root.join("block/dm-0/slaves/sdb")).unwrap();
+
+        fs::create_dir_all(&dev_root).unwrap();
+        fs::write(dev_root.join("sda"), b"").unwrap();
+        fs::write(dev_root.join("sdb"), b"").unwrap();
+
+        let resolver = DeviceResolver::new()
+            .with_sysfs_root(sysfs_root)
+            .with_dev_root(dev_root.clone());
+
+        let mut physical = resolver.resolve_physical_devices_mm(253, 0).unwrap();
+        physical.sort();
+        assert_eq!(physical, vec![dev_root.join("sda"), dev_root.join("sdb")]);
     }
 
     #[test]
@@ -463,4 +1299,89 @@ mod tests {
             assert!(device.to_string_lossy().starts_with("/dev"));
         }
     }
+
+    #[test]
+    fn test_resolve_disk_for_root() {
+        // Root filesystem should always be resolvable to some disk, whether
+        // root itself lives on a partition or a whole disk.
+        let path = Path::new("/");
+        let result = path.resolve_disk();
+        if result.is_ok() {
+            let disk = result.unwrap();
+            assert!(disk.to_string_lossy().starts_with("/dev"));
+        }
+    }
+
+    #[test]
+    fn test_resolve_device_info_with_fixtures() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_root = temp_dir.path().join("sys");
+        let dev_root = temp_dir.path().join("dev");
+
+        // /sys/dev/block/8:1 -> ../../block/sda/sda1, a partition of sda.
+        fs::create_dir_all(sysfs_root.join("dev/block")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sda/sda1")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sda/device")).unwrap();
+        fs::write(sysfs_root.join("block/sda/sda1/partition"), b"1").unwrap();
+        std::os::unix::fs::symlink("../../block/sda/sda1", sysfs_root.join("dev/block/8:1"))
+            .unwrap();
+        fs::write(sysfs_root.join("block/sda/device/model"), b"Fixture Disk\n").unwrap();
+        fs::write(sysfs_root.join("block/sda/device/serial"), b"FX123\n").unwrap();
+
+        fs::create_dir_all(dev_root.join("disk/by-id")).unwrap();
+        fs::write(dev_root.join("sda1"), b"").unwrap();
+        std::os::unix::fs::symlink("../../sda1", dev_root.join("disk/by-id/fixture-disk-part1"))
+            .unwrap();
+
+        let resolver = DeviceResolver::new()
+            .with_sysfs_root(sysfs_root)
+            .with_dev_root(dev_root.clone());
+
+        let info = resolver.resolve_device_info_mm(8, 1).unwrap();
+        assert_eq!(info.path, dev_root.join("sda1"));
+        assert_eq!(
+            info.by_id,
+            vec![dev_root.join("disk/by-id/fixture-disk-part1")]
+        );
+        assert_eq!(info.model.as_deref(), Some("Fixture Disk"));
+        assert_eq!(info.serial.as_deref(), Some("FX123"));
+        assert_eq!(info.wwid, None);
+    }
+
+    #[test]
+    fn test_resolve_device_attributes_for_partition() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_root = temp_dir.path().join("sys");
+
+        // /sys/dev/block/8:1 -> ../../block/sda/sda1, a partition of sda.
+        fs::create_dir_all(sysfs_root.join("dev/block")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sda/sda1")).unwrap();
+        fs::create_dir_all(sysfs_root.join("block/sda/queue")).unwrap();
+        fs::write(sysfs_root.join("block/sda/sda1/partition"), b"1").unwrap();
+        fs::write(sysfs_root.join("block/sda/size"), b"2048\n").unwrap();
+        fs::write(sysfs_root.join("block/sda/queue/rotational"), b"1\n").unwrap();
+        std::os::unix::fs::symlink("../../block/sda/sda1", sysfs_root.join("dev/block/8:1"))
+            .unwrap();
+
+        let resolver = DeviceResolver::new().with_sysfs_root(sysfs_root);
+
+        let attrs = resolver.resolve_device_attributes_mm(8, 1).unwrap();
+        assert_eq!(attrs.kind, DeviceKind::Partition);
+        assert_eq!(attrs.size_bytes, 2048 * 512);
+        assert!(attrs.rotational);
+    }
+
+    #[test]
+    fn test_resolve_device_attributes_for_virtual_device() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_root = temp_dir.path().join("sys");
+        fs::create_dir_all(sysfs_root.join("dev/block")).unwrap();
+
+        let resolver = DeviceResolver::new().with_sysfs_root(sysfs_root);
+
+        let attrs = resolver.resolve_device_attributes_mm(0, 21).unwrap();
+        assert_eq!(attrs.kind, DeviceKind::Virtual);
+        assert_eq!(attrs.size_bytes, 0);
+        assert!(!attrs.rotational);
+    }
 }